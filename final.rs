@@ -1,16 +1,32 @@
 extern crate csv;
 extern crate k_means;
 extern crate chrono;
+extern crate chrono_tz;
+extern crate clap;
 extern crate plotters;
+extern crate ordered_float;
+extern crate rayon;
 
-use std::collections::{HashMap, HashSet};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet};
 use std::error::Error;
 use std::fs::File;
 use k_means::{KMeans, Point};
-use chrono::{NaiveDateTime, Datelike};
+use chrono::{Datelike, NaiveDate, NaiveDateTime, TimeZone};
+use chrono_tz::America::Chicago;
+use clap::Parser;
 use plotters::prelude::*;
+use ordered_float::OrderedFloat;
+use rayon::prelude::*;
 
-#[derive(Debug, serde::Deserialize)]
+/// Mean radius of the Earth in kilometers, used by `haversine_distance`.
+const EARTH_RADIUS_KM: f64 = 6371.0;
+
+/// Floor on how small a parallel parse chunk is allowed to get, so tiny
+/// inputs don't get split into one-row chunks with no real parallelism gain.
+const MIN_PARSE_CHUNK_SIZE: usize = 256;
+
+#[derive(Debug, Clone, serde::Deserialize)]
 struct CrimeRecord {
     ID: String,
     Case_Number: String,
@@ -30,67 +46,259 @@ struct CrimeRecord {
     X_Coordinate: Option<f64>,
     Y_Coordinate: Option<f64>,
     Year: i32,
-    Updated_On: String,
+    Updated_On: NaiveDate,
     Latitude: Option<f64>,
     Longitude: Option<f64>,
     Location: String,
 }
 
+/// Mirrors `CrimeRecord` field-for-field but typed as the CSV actually hands
+/// it to us: every field that isn't already a plain string (`Date`,
+/// `Updated_On`, `Arrest`, `Domestic`, the coordinate columns) stays a
+/// `String` here so serde can deserialize it without help; `Year` keeps its
+/// `i32` type. `clean_record` turns one of these into a `CrimeRecord`.
+#[derive(Debug, serde::Deserialize)]
+struct RawCrimeRecord {
+    ID: String,
+    Case_Number: String,
+    Date: String,
+    Block: String,
+    IUCR: String,
+    Primary_Type: String,
+    Description: String,
+    Location_Description: String,
+    Arrest: String,
+    Domestic: String,
+    Beat: String,
+    District: String,
+    Ward: String,
+    Community_Area: String,
+    FBI_Code: String,
+    X_Coordinate: String,
+    Y_Coordinate: String,
+    Year: i32,
+    Updated_On: String,
+    Latitude: String,
+    Longitude: String,
+    Location: String,
+}
+
+/// One ingested record paired with the strictly increasing ordinal it was
+/// assigned at insert time.
+struct OrderedRecord {
+    ordinal: u64,
+    record: CrimeRecord,
+}
+
+/// Remembers the highest ordinal a consumer has already seen from a
+/// `CrimeDataset`. Pass the same `Cursor` to repeated `since` calls to pick
+/// up only newly ingested records each time.
+struct Cursor {
+    last_seen: Option<u64>,
+}
+
+impl Cursor {
+    fn new() -> Self {
+        Cursor { last_seen: None }
+    }
+}
+
+/// Append-only store of `CrimeRecord`s that stamps each insert with a
+/// strictly increasing ordinal. Unlike the wall-clock `Date`/`Updated_On`
+/// fields, which aren't monotonic and can repeat across batches, the ordinal
+/// guarantees `since` never returns a record twice and never skips one, even
+/// when new batches arrive out of date order.
+struct CrimeDataset {
+    records: Vec<OrderedRecord>,
+    next_ordinal: u64,
+}
+
+impl CrimeDataset {
+    fn new() -> Self {
+        CrimeDataset {
+            records: Vec::new(),
+            next_ordinal: 0,
+        }
+    }
+
+    /// Stamps each of `new_records` with the next ordinal and appends it.
+    fn ingest(&mut self, new_records: Vec<CrimeRecord>) {
+        for record in new_records {
+            self.records.push(OrderedRecord {
+                ordinal: self.next_ordinal,
+                record,
+            });
+            self.next_ordinal += 1;
+        }
+    }
+
+    /// Returns every record with an ordinal greater than `cursor`'s last-seen
+    /// value, in ingestion order, then advances `cursor` past them.
+    fn since(&self, cursor: &mut Cursor) -> Vec<&CrimeRecord> {
+        let new_records: Vec<&CrimeRecord> = self
+            .records
+            .iter()
+            .filter(|entry| Some(entry.ordinal) > cursor.last_seen)
+            .map(|entry| &entry.record)
+            .collect();
+
+        if let Some(highest) = self.records.iter().map(|entry| entry.ordinal).max() {
+            cursor.last_seen = Some(highest);
+        }
+
+        new_records
+    }
+}
+
+/// Parses a `%m/%d/%y %H:%M` timestamp, under the assumption — true of this
+/// dataset, not verified here — that the string already represents a wall
+/// clock reading in America/Chicago. That assumption means there is no zone
+/// to convert *from*, so the returned value is the same naive (unzoned)
+/// `NaiveDateTime` that was parsed; chrono-tz is not changing which calendar
+/// day or instant the timestamp maps to. Its one real effect is catching
+/// timestamps that fall in a Chicago DST gap (a local time that never
+/// occurred, e.g. during the spring-forward transition) and rejecting them
+/// with a clear error instead of silently treating them as valid.
+fn parse_chicago_datetime(input: &str, format: &str) -> Result<NaiveDateTime, Box<dyn Error + Send + Sync>> {
+    let naive = NaiveDateTime::parse_from_str(input, format)?;
+    let localized = match Chicago.from_local_datetime(&naive) {
+        chrono::LocalResult::Single(dt) => dt,
+        chrono::LocalResult::Ambiguous(earliest, _latest) => earliest,
+        chrono::LocalResult::None => {
+            return Err(format!("'{}' does not exist in America/Chicago (DST gap)", input).into())
+        }
+    };
+    Ok(localized.naive_local())
+}
+
+/// Parses a date-only (`%m/%d/%Y`) value such as `Updated_On`, under the same
+/// already-Chicago-local assumption as `parse_chicago_datetime`. A bare date
+/// has no time-of-day component to fall in a DST gap, so there is nothing to
+/// validate against chrono-tz here; this exists only so `Updated_On` goes
+/// through the same typed parsing step as `Date` rather than staying an
+/// unparsed `String`.
+fn parse_chicago_date(input: &str, format: &str) -> Result<NaiveDate, Box<dyn Error + Send + Sync>> {
+    Ok(NaiveDate::parse_from_str(input, format)?)
+}
+
+/// The America/Chicago calendar date an incident's `Date` falls on, used
+/// anywhere records get bucketed by day.
+fn local_date(date: &NaiveDateTime) -> NaiveDate {
+    date.date()
+}
+
+/// Applies the same cleaning/transforming steps `read_data` used to apply
+/// inline, to one deserialized row. Pulled out so it can run independently
+/// across parallel chunks.
+fn clean_record(raw: RawCrimeRecord) -> Result<CrimeRecord, Box<dyn Error + Send + Sync>> {
+    // Cleaning and transforming data
+    let arrest = raw.Arrest == "TRUE"; // Converting "TRUE" to true
+    let domestic = raw.Domestic == "TRUE"; // Converting "TRUE" to true
+    let date = parse_chicago_datetime(&raw.Date, "%m/%d/%y %H:%M")?; // Parsing date string, America/Chicago
+    let year = date.year(); // Extracting year from date
+    let updated_on = parse_chicago_date(&raw.Updated_On, "%m/%d/%Y")?; // Parsing Updated_On, date-only
+
+    // Converting empty strings to None for numeric fields
+    let x_coordinate = if raw.X_Coordinate.is_empty() {
+        None
+    } else {
+        Some(raw.X_Coordinate.parse()?)
+    };
+    let y_coordinate = if raw.Y_Coordinate.is_empty() {
+        None
+    } else {
+        Some(raw.Y_Coordinate.parse()?)
+    };
+    let latitude = if raw.Latitude.is_empty() {
+        None
+    } else {
+        Some(raw.Latitude.parse()?)
+    };
+    let longitude = if raw.Longitude.is_empty() {
+        None
+    } else {
+        Some(raw.Longitude.parse()?)
+    };
+
+    Ok(CrimeRecord {
+        ID: raw.ID,
+        Case_Number: raw.Case_Number,
+        Date: date,
+        Block: raw.Block,
+        IUCR: raw.IUCR,
+        Primary_Type: raw.Primary_Type,
+        Description: raw.Description,
+        Location_Description: raw.Location_Description,
+        Arrest: arrest,
+        Domestic: domestic,
+        Beat: raw.Beat,
+        District: raw.District,
+        Ward: raw.Ward,
+        Community_Area: raw.Community_Area,
+        FBI_Code: raw.FBI_Code,
+        X_Coordinate: x_coordinate,
+        Y_Coordinate: y_coordinate,
+        Year: year,
+        Updated_On: updated_on,
+        Latitude: latitude,
+        Longitude: longitude,
+        Location: raw.Location,
+    })
+}
+
 fn read_data(file_path: &str) -> Result<Vec<CrimeRecord>, Box<dyn Error>> {
     let file = File::open(file_path)?;
     let mut reader = csv::Reader::from_reader(file);
-    let mut records = Vec::new();
-
-    for result in reader.deserialize() {
-        let mut record: CrimeRecord = result?;
-
-        // Cleaning and transforming data
-        record.Arrest = record.Arrest == "TRUE"; // Converting "TRUE" to true
-        record.Domestic = record.Domestic == "TRUE"; // Converting "TRUE" to true
-        record.Date = NaiveDateTime::parse_from_str(&record.Date, "%m/%d/%y %H:%M")?; // Parsing date string
-        record.Year = record.Date.year(); // Extracting year from date
-
-        // Converting empty strings to None for numeric fields
-        record.X_Coordinate = if record.X_Coordinate.is_empty() {
-            None
-        } else {
-            Some(record.X_Coordinate.parse().unwrap())
-        };
-        record.Y_Coordinate = if record.Y_Coordinate.is_empty() {
-            None
-        } else {
-            Some(record.Y_Coordinate.parse().unwrap())
-        };
-        record.Latitude = if record.Latitude.is_empty() {
-            None
-        } else {
-            Some(record.Latitude.parse().unwrap())
-        };
-        record.Longitude = if record.Longitude.is_empty() {
-            None
-        } else {
-            Some(record.Longitude.parse().unwrap())
-        };
+    let headers = reader.headers()?.clone();
+    let rows: Vec<csv::StringRecord> = reader.records().collect::<Result<Vec<_>, _>>()?;
 
-        records.push(record);
-    }
+    // Split the input into chunks sized off the available parallelism, so
+    // parsing and cleaning scale with cores instead of running single-threaded.
+    let thread_count = rayon::current_num_threads().max(1);
+    let chunk_size = (rows.len() / thread_count).max(MIN_PARSE_CHUNK_SIZE);
+
+    let records = rows
+        .par_chunks(chunk_size)
+        .map(|chunk| -> Result<Vec<CrimeRecord>, Box<dyn Error + Send + Sync>> {
+            chunk
+                .iter()
+                .map(|row| {
+                    let raw: RawCrimeRecord = row.deserialize(Some(&headers))?;
+                    clean_record(raw)
+                })
+                .collect()
+        })
+        .collect::<Result<Vec<Vec<CrimeRecord>>, _>>()?
+        .into_iter()
+        .flatten()
+        .collect();
 
     Ok(records)
 }
 
 fn build_adjacency_list(records: &[CrimeRecord]) -> HashMap<String, HashSet<String>> {
-    let mut adjacency_list: HashMap<String, HashSet<String>> = HashMap::new();
-
+    // First pass: group incident IDs by date so the second pass never has to
+    // rescan the full record set. This makes the whole build O(n + sum of
+    // per-day bucket sizes squared) instead of O(n^2).
+    let mut same_day_groups: HashMap<NaiveDate, Vec<String>> = HashMap::new();
     for record in records {
-        let incident_node = &record.ID;
-        let related_nodes: HashSet<_> = records
-            .iter()
-            .filter(|&r| r.ID != *incident_node) // Excluding the incident node itself
-            .filter(|&r| r.Date.date() == record.Date.date()) // 
-            .map(|r| r.ID.clone())
-            .collect();
+        same_day_groups
+            .entry(local_date(&record.Date))
+            .or_insert_with(Vec::new)
+            .push(record.ID.clone());
+    }
 
-        adjacency_list.insert(incident_node.clone(), related_nodes);
+    let mut adjacency_list: HashMap<String, HashSet<String>> = HashMap::new();
+    for ids in same_day_groups.values() {
+        for incident_node in ids {
+            let related_nodes: HashSet<String> = ids
+                .iter()
+                .filter(|&other| other != incident_node) // Excluding the incident node itself
+                .cloned()
+                .collect();
+
+            adjacency_list.insert(incident_node.clone(), related_nodes);
+        }
     }
 
     adjacency_list
@@ -122,11 +330,106 @@ fn six_degrees_of_distribution(
     visited
 }
 
-fn plot_temporal_trends(records: &[CrimeRecord]) {
+/// Great-circle distance in kilometers between two lat/long points, via the
+/// haversine formula.
+fn haversine_distance(lat1: f64, lon1: f64, lat2: f64, lon2: f64) -> f64 {
+    let phi1 = lat1.to_radians();
+    let phi2 = lat2.to_radians();
+    let delta_phi = (lat2 - lat1).to_radians();
+    let delta_lambda = (lon2 - lon1).to_radians();
+
+    let a = (delta_phi / 2.0).sin().powi(2)
+        + phi1.cos() * phi2.cos() * (delta_lambda / 2.0).sin().powi(2);
+
+    2.0 * EARTH_RADIUS_KM * a.sqrt().asin()
+}
+
+/// Builds a same-day graph like `build_adjacency_list`, but weights each edge
+/// by the great-circle distance between the two incidents' `Latitude`/`Longitude`.
+/// Records missing either coordinate are skipped entirely.
+fn build_weighted_adjacency_list(records: &[CrimeRecord]) -> HashMap<String, Vec<(String, f64)>> {
+    let mut weighted_adjacency_list: HashMap<String, Vec<(String, f64)>> = HashMap::new();
+
+    for record in records {
+        let (lat, lon) = match (record.Latitude, record.Longitude) {
+            (Some(lat), Some(lon)) => (lat, lon),
+            _ => continue,
+        };
+
+        let incident_node = &record.ID;
+        let mut edges = Vec::new();
+
+        for other in records {
+            if other.ID == *incident_node || local_date(&other.Date) != local_date(&record.Date) {
+                continue;
+            }
+
+            let (other_lat, other_lon) = match (other.Latitude, other.Longitude) {
+                (Some(lat), Some(lon)) => (lat, lon),
+                _ => continue,
+            };
+
+            edges.push((other.ID.clone(), haversine_distance(lat, lon, other_lat, other_lon)));
+        }
+
+        weighted_adjacency_list.insert(incident_node.clone(), edges);
+    }
+
+    weighted_adjacency_list
+}
+
+/// Finds the tightest geographically-weighted chain of same-day incidents
+/// linking `start` to `goal`, via Dijkstra over `graph`. Returns the path
+/// (inclusive of both endpoints) along with its total distance in kilometers,
+/// or `None` if `goal` is unreachable from `start`.
+fn shortest_path(
+    graph: &HashMap<String, Vec<(String, f64)>>,
+    start: &str,
+    goal: &str,
+) -> Option<(Vec<String>, f64)> {
+    let mut dist: HashMap<String, f64> = HashMap::new();
+    let mut prev: HashMap<String, String> = HashMap::new();
+    let mut heap: BinaryHeap<Reverse<(OrderedFloat<f64>, String)>> = BinaryHeap::new();
+
+    dist.insert(start.to_owned(), 0.0);
+    heap.push(Reverse((OrderedFloat(0.0), start.to_owned())));
+
+    while let Some(Reverse((cost, node))) = heap.pop() {
+        if node == goal {
+            let mut path = vec![node.clone()];
+            let mut current = node.as_str();
+            while let Some(previous) = prev.get(current) {
+                path.push(previous.clone());
+                current = previous;
+            }
+            path.reverse();
+            return Some((path, cost.into_inner()));
+        }
+
+        if cost.into_inner() > *dist.get(&node).unwrap_or(&f64::INFINITY) {
+            continue;
+        }
+
+        if let Some(neighbors) = graph.get(&node) {
+            for (neighbor, weight) in neighbors {
+                let next_cost = cost.into_inner() + weight;
+                if next_cost < *dist.get(neighbor).unwrap_or(&f64::INFINITY) {
+                    dist.insert(neighbor.clone(), next_cost);
+                    prev.insert(neighbor.clone(), node.clone());
+                    heap.push(Reverse((OrderedFloat(next_cost), neighbor.clone())));
+                }
+            }
+        }
+    }
+
+    None
+}
+
+fn plot_temporal_trends(records: &[CrimeRecord], plot_path: &str) {
     let mut date_counts: HashMap<NaiveDateTime, usize> = HashMap::new();
 
     for record in records {
-        let date = record.Date.date();
+        let date = local_date(&record.Date);
         date_counts.entry(date).and_modify(|c| *c += 1).or_insert(1);
     }
 
@@ -139,7 +442,7 @@ fn plot_temporal_trends(records: &[CrimeRecord]) {
     }
 
     // Plotting a histogram of temporal trends
-    let root_area = BitMapBackend::new("temporal_trends.png", (800, 600)).into_drawing_area();
+    let root_area = BitMapBackend::new(plot_path, (800, 600)).into_drawing_area();
     root_area.fill(&WHITE).unwrap();
     let mut chart = ChartBuilder::on(&root_area)
         .caption("Temporal Trends", ("sans-serif", 20).into_font())
@@ -166,24 +469,257 @@ fn plot_temporal_trends(records: &[CrimeRecord]) {
         .unwrap();
 }
 
+/// Euclidean distance between two k-means points, via their underlying
+/// coordinate vectors.
+fn point_distance(a: &Point<f64>, b: &Point<f64>) -> f64 {
+    a.values()
+        .iter()
+        .zip(b.values().iter())
+        .map(|(x, y)| (x - y).powi(2))
+        .sum::<f64>()
+        .sqrt()
+}
+
+/// Mean distance from `point` to every point in `others`; 0.0 if `others` is empty.
+fn mean_distance<'a>(point: &Point<f64>, others: impl Iterator<Item = &'a Point<f64>>) -> f64 {
+    let mut total = 0.0;
+    let mut count = 0usize;
+    for other in others {
+        total += point_distance(point, other);
+        count += 1;
+    }
+    if count == 0 {
+        0.0
+    } else {
+        total / count as f64
+    }
+}
+
+/// Mean silhouette coefficient for a clustering: for each point, `a` is its
+/// mean distance to the other members of its own cluster and `b` is the
+/// minimum, over every other cluster, of its mean distance to that cluster's
+/// members. The point's silhouette is `(b - a) / max(a, b)`; this returns the
+/// average over all points (0.0 if there are no points to score).
+fn mean_silhouette(cluster_points: &[Vec<&Point<f64>>]) -> f64 {
+    let mut total = 0.0;
+    let mut count = 0usize;
+
+    for (cluster_idx, members) in cluster_points.iter().enumerate() {
+        if members.is_empty() {
+            continue;
+        }
+
+        for (point_idx, point) in members.iter().enumerate() {
+            // A singleton has no other member to measure a within-cluster
+            // distance against; by convention its silhouette is 0, not the
+            // (b - 0) / b = 1.0 that `a == 0` would otherwise produce.
+            let silhouette = if members.len() == 1 {
+                0.0
+            } else {
+                let a = mean_distance(
+                    *point,
+                    members
+                        .iter()
+                        .enumerate()
+                        .filter(|(idx, _)| *idx != point_idx)
+                        .map(|(_, p)| *p),
+                );
+                let b = cluster_points
+                    .iter()
+                    .enumerate()
+                    .filter(|(idx, other_members)| *idx != cluster_idx && !other_members.is_empty())
+                    .map(|(_, other_members)| mean_distance(*point, other_members.iter().copied()))
+                    .fold(f64::INFINITY, f64::min);
+
+                if !b.is_finite() {
+                    // No other non-empty cluster to compare against.
+                    0.0
+                } else {
+                    let denom = a.max(b);
+                    if denom == 0.0 {
+                        0.0
+                    } else {
+                        (b - a) / denom
+                    }
+                }
+            };
+
+            total += silhouette;
+            count += 1;
+        }
+    }
+
+    if count == 0 {
+        0.0
+    } else {
+        total / count as f64
+    }
+}
+
+/// Fits K-means for every candidate k in `k_range`, scores each result with
+/// the mean silhouette coefficient, and returns the k with the highest score
+/// (ties broken toward the smaller k). Prints each candidate's score so the
+/// elbow is visible.
+fn choose_k(points: &[Point<f64>], k_range: std::ops::RangeInclusive<usize>) -> usize {
+    let mut best_k = *k_range.start();
+    let mut best_score = f64::NEG_INFINITY;
+
+    for k in k_range {
+        let kmeans = KMeans::new(points, k);
+        let clusters = kmeans.fit();
+        let cluster_points: Vec<Vec<&Point<f64>>> =
+            clusters.iter().map(|c| c.points().into_iter().collect()).collect();
+
+        let score = mean_silhouette(&cluster_points);
+        println!("k = {}: mean silhouette = {:.4}", k, score);
+
+        if score > best_score {
+            best_score = score;
+            best_k = k;
+        }
+    }
+
+    best_k
+}
+
+/// Command-line options for exploring a Chicago crime incident extract:
+/// clustering, temporal trends, and same-day reachability.
+#[derive(Parser, Debug)]
+#[command(author, version, about = "Explore Chicago crime incident data")]
+struct Cli {
+    /// Path to the crime-data CSV to analyze
+    #[arg(long, default_value = "chicago_crimes_sample_1.csv")]
+    input: String,
+
+    /// Number of clusters for K-means; ignored when --auto-k is set
+    #[arg(long, default_value_t = 3)]
+    clusters: usize,
+
+    /// Pick the number of clusters automatically by sweeping [k-min, k-max]
+    /// and choosing the k with the highest mean silhouette score
+    #[arg(long, default_value_t = false)]
+    auto_k: bool,
+
+    /// Smallest k to try when --auto-k is set
+    #[arg(long, default_value_t = 2)]
+    k_min: usize,
+
+    /// Largest k to try when --auto-k is set
+    #[arg(long, default_value_t = 10)]
+    k_max: usize,
+
+    /// Only include incidents on or after this date (YYYY-MM-DD, America/Chicago)
+    #[arg(long)]
+    from: Option<NaiveDate>,
+
+    /// Only include incidents on or before this date (YYYY-MM-DD, America/Chicago)
+    #[arg(long)]
+    to: Option<NaiveDate>,
+
+    /// Incident ID to start the reachability sweep from; defaults to the first record
+    #[arg(long)]
+    start_node: Option<String>,
+
+    /// Incident ID to find the tightest geographically-weighted same-day
+    /// chain to, starting from --start-node
+    #[arg(long)]
+    path_to: Option<String>,
+
+    /// Where to write the temporal-trends histogram
+    #[arg(long, default_value = "temporal_trends.png")]
+    plot_out: String,
+
+    /// Path to a small file remembering the ordinal of the last-processed
+    /// record. When set, only records ingested since that ordinal (i.e. new
+    /// rows appended to --input since the previous run) are clustered and
+    /// plotted, and the file is updated to the new high-water mark as soon
+    /// as that delta has been selected, before any of the analysis runs.
+    /// Omit to always process the full input.
+    #[arg(long)]
+    cursor_file: Option<String>,
+}
+
+/// Reads the ordinal persisted by a previous run from `path`, if any. A
+/// missing or unparsable file is treated as "nothing seen yet" rather than
+/// an error, since the first run naturally has no cursor file.
+fn load_cursor(path: &str) -> Cursor {
+    std::fs::read_to_string(path)
+        .ok()
+        .and_then(|contents| contents.trim().parse::<u64>().ok())
+        .map(|last_seen| Cursor { last_seen: Some(last_seen) })
+        .unwrap_or_else(Cursor::new)
+}
+
+/// Persists `cursor`'s high-water mark to `path` so the next run can resume
+/// from it. Does nothing if nothing has been seen yet.
+fn save_cursor(path: &str, cursor: &Cursor) -> std::io::Result<()> {
+    match cursor.last_seen {
+        Some(last_seen) => std::fs::write(path, last_seen.to_string()),
+        None => Ok(()),
+    }
+}
+
 fn main() {
-    let file_path = "chicago_crimes_sample_1.csv";
-    match read_data(file_path) {
+    let cli = Cli::parse();
+
+    if let (Some(from), Some(to)) = (cli.from, cli.to) {
+        if to < from {
+            eprintln!("--to ({}) cannot be earlier than --from ({})", to, from);
+            std::process::exit(1);
+        }
+    }
+
+    match read_data(&cli.input) {
         Ok(records) => {
+            // Ingesting into a CrimeDataset so --cursor-file can ask for only the
+            // delta of a growing feed instead of reprocessing everything each run.
+            let mut dataset = CrimeDataset::new();
+            dataset.ingest(records);
+
+            let records: Vec<CrimeRecord> = match &cli.cursor_file {
+                Some(cursor_path) => {
+                    let mut cursor = load_cursor(cursor_path);
+                    let delta: Vec<CrimeRecord> = dataset.since(&mut cursor).into_iter().cloned().collect();
+                    if let Err(e) = save_cursor(cursor_path, &cursor) {
+                        eprintln!("Warning: failed to persist cursor to {}: {}", cursor_path, e);
+                    }
+                    delta
+                }
+                None => dataset.records.into_iter().map(|entry| entry.record).collect(),
+            };
+
+            // Filtering by the requested date range before clustering, plotting or graph building
+            let records: Vec<_> = records
+                .into_iter()
+                .filter(|r| {
+                    let date = local_date(&r.Date);
+                    cli.from.map_or(true, |from| date >= from) && cli.to.map_or(true, |to| date <= to)
+                })
+                .collect();
+
             // Filtering out records with missing coordinates
             let valid_records: Vec<_> = records
                 .into_iter()
                 .filter(|r| r.X_Coordinate.is_some() && r.Y_Coordinate.is_some())
                 .collect();
 
+            if valid_records.is_empty() {
+                eprintln!("No incidents with coordinates in the given date range; nothing to cluster or plot");
+                return;
+            }
+
             // Preparing points for clustering
             let points: Vec<Point<_>> = valid_records
                 .iter()
                 .map(|r| Point::new(vec![r.X_Coordinate.unwrap(), r.Y_Coordinate.unwrap()]))
                 .collect();
 
-            // Performing K-means clustering with k=3
-            let k = 3;
+            // Performing K-means clustering
+            let k = if cli.auto_k {
+                choose_k(&points, cli.k_min..=cli.k_max)
+            } else {
+                cli.clusters
+            };
             let kmeans = KMeans::new(&points, k);
             let clusters = kmeans.fit();
 
@@ -199,69 +735,149 @@ fn main() {
             }
 
             // Performing temporal trend analysis
-            plot_temporal_trends(&valid_records);
+            plot_temporal_trends(&valid_records, &cli.plot_out);
 
             // Building adjacency list and analyzing six degrees of distribution
             let adjacency_list = build_adjacency_list(&valid_records);
-            let start_node = valid_records[0].ID.clone(); // Choosing the first record as the starting node
+            let start_node = cli
+                .start_node
+                .clone()
+                .unwrap_or_else(|| valid_records[0].ID.clone()); // Defaulting to the first record as the starting node
             let related_nodes = six_degrees_of_distribution(&adjacency_list, &start_node);
             println!("Six Degrees of Distribution (starting from {})", start_node);
-            
-            
-         #[cfg(test)]
-            mod tests {
-                use super::*;
-                use chrono::NaiveDate;
-                use std::fs::{self, File};
-                use std::path::Path;
-            
-                fn create_sample_record() -> CrimeRecord {
-                    CrimeRecord {
-                        ID: "1".to_string(),
-                        Case_Number: "H123".to_string(),
-                        Date: NaiveDateTime::new(NaiveDate::from_ymd(2020, 1, 1), chrono::NaiveTime::from_hms(12, 0, 0)),
-                        Block: "100 XX BLOCK".to_string(),
-                        IUCR: "0510".to_string(),
-                        Primary_Type: "ASSAULT".to_string(),
-                        Description: "AGGRAVATED: HANDGUN".to_string(),
-                        Location_Description: "STREET".to_string(),
-                        Arrest: true,
-                        Domestic: false,
-                        Beat: "123".to_string(),
-                        District: "10".to_string(),
-                        Ward: "1".to_string(),
-                        Community_Area: "32".to_string(),
-                        FBI_Code: "04A".to_string(),
-                        X_Coordinate: Some(1155643.0),
-                        Y_Coordinate: Some(1924568.0),
-                        Year: 2020,
-                        Updated_On: "01/01/2021".to_string(),
-                        Latitude: Some(41.891398861),
-                        Longitude: Some(-87.744384567),
-                        Location: "41.891398861, -87.744384567".to_string(),
+            println!("{:?}", related_nodes);
+
+            // Finding the tightest geographically-weighted same-day chain to --path-to, if requested
+            if let Some(goal) = &cli.path_to {
+                let weighted_adjacency_list = build_weighted_adjacency_list(&valid_records);
+                match shortest_path(&weighted_adjacency_list, &start_node, goal) {
+                    Some((path, distance_km)) => {
+                        println!(
+                            "Shortest path from {} to {} ({:.3} km): {:?}",
+                            start_node, goal, distance_km, path
+                        );
+                    }
+                    None => {
+                        println!("No same-day geographic path from {} to {}", start_node, goal);
                     }
-                }
-            
-                #[test]
-                fn test_plot_temporal_trends() {
-                    let records = vec![create_sample_record()];
-                    let plot_path = "temporal_trends.png";
-            
-                    // Removing the file if it already exists to start with a clean state
-                    let _ = fs::remove_file(plot_path);
-            
-                    // Generating the plot
-                    plot_temporal_trends(&records);
-            
-                    // Checking if the file has been created
-                    assert!(Path::new(plot_path).exists(), "The plot file was not created");
-            
-                    // Optionally, checking the file size to make sure it's not empty
-                    let metadata = fs::metadata(plot_path).expect("Failed to retrieve file metadata");
-                    assert!(metadata.len() > 0, "The plot file is empty");
-            
-                    // Cleaning up by removing the file after testing
-                    let _ = fs::remove_file(plot_path);
                 }
             }
-            
\ No newline at end of file
+        }
+        Err(e) => eprintln!("Error reading data from {}: {}", cli.input, e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+    use std::fs;
+    use std::path::Path;
+
+    fn create_sample_record() -> CrimeRecord {
+        CrimeRecord {
+            ID: "1".to_string(),
+            Case_Number: "H123".to_string(),
+            Date: NaiveDateTime::new(NaiveDate::from_ymd(2020, 1, 1), chrono::NaiveTime::from_hms(12, 0, 0)),
+            Block: "100 XX BLOCK".to_string(),
+            IUCR: "0510".to_string(),
+            Primary_Type: "ASSAULT".to_string(),
+            Description: "AGGRAVATED: HANDGUN".to_string(),
+            Location_Description: "STREET".to_string(),
+            Arrest: true,
+            Domestic: false,
+            Beat: "123".to_string(),
+            District: "10".to_string(),
+            Ward: "1".to_string(),
+            Community_Area: "32".to_string(),
+            FBI_Code: "04A".to_string(),
+            X_Coordinate: Some(1155643.0),
+            Y_Coordinate: Some(1924568.0),
+            Year: 2020,
+            Updated_On: NaiveDate::from_ymd(2021, 1, 1),
+            Latitude: Some(41.891398861),
+            Longitude: Some(-87.744384567),
+            Location: "41.891398861, -87.744384567".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_plot_temporal_trends() {
+        let records = vec![create_sample_record()];
+        let plot_path = "temporal_trends.png";
+
+        // Removing the file if it already exists to start with a clean state
+        let _ = fs::remove_file(plot_path);
+
+        // Generating the plot
+        plot_temporal_trends(&records, plot_path);
+
+        // Checking if the file has been created
+        assert!(Path::new(plot_path).exists(), "The plot file was not created");
+
+        // Optionally, checking the file size to make sure it's not empty
+        let metadata = fs::metadata(plot_path).expect("Failed to retrieve file metadata");
+        assert!(metadata.len() > 0, "The plot file is empty");
+
+        // Cleaning up by removing the file after testing
+        let _ = fs::remove_file(plot_path);
+    }
+
+    #[test]
+    fn test_haversine_distance_known_pair() {
+        // One degree of longitude at the equator is ~111.19 km.
+        let distance = haversine_distance(0.0, 0.0, 0.0, 1.0);
+        assert!(
+            (distance - 111.19).abs() < 0.1,
+            "expected ~111.19 km, got {}",
+            distance
+        );
+
+        assert_eq!(haversine_distance(41.89, -87.74, 41.89, -87.74), 0.0);
+    }
+
+    #[test]
+    fn test_shortest_path_prefers_cheaper_multi_hop_route() {
+        let mut graph: HashMap<String, Vec<(String, f64)>> = HashMap::new();
+        graph.insert("A".to_string(), vec![("B".to_string(), 2.0), ("C".to_string(), 10.0)]);
+        graph.insert("B".to_string(), vec![("A".to_string(), 2.0), ("C".to_string(), 3.0)]);
+        graph.insert("C".to_string(), vec![("B".to_string(), 3.0), ("A".to_string(), 10.0)]);
+
+        let (path, distance) = shortest_path(&graph, "A", "C").expect("path should exist");
+        assert_eq!(path, vec!["A".to_string(), "B".to_string(), "C".to_string()]);
+        assert_eq!(distance, 5.0);
+    }
+
+    #[test]
+    fn test_shortest_path_returns_none_when_unreachable() {
+        let mut graph: HashMap<String, Vec<(String, f64)>> = HashMap::new();
+        graph.insert("A".to_string(), vec![("B".to_string(), 1.0)]);
+        graph.insert("B".to_string(), vec![("A".to_string(), 1.0)]);
+
+        assert!(shortest_path(&graph, "A", "Z").is_none());
+    }
+
+    #[test]
+    fn test_since_returns_each_record_exactly_once_across_batches() {
+        let mut first_record = create_sample_record();
+        first_record.ID = "1".to_string();
+        let mut second_record = create_sample_record();
+        second_record.ID = "2".to_string();
+        let mut third_record = create_sample_record();
+        third_record.ID = "3".to_string();
+
+        let mut dataset = CrimeDataset::new();
+        let mut cursor = Cursor::new();
+
+        dataset.ingest(vec![first_record, second_record]);
+        let first_batch: Vec<String> = dataset.since(&mut cursor).iter().map(|r| r.ID.clone()).collect();
+        assert_eq!(first_batch, vec!["1".to_string(), "2".to_string()]);
+
+        // Nothing new since the cursor was last advanced.
+        assert!(dataset.since(&mut cursor).is_empty());
+
+        dataset.ingest(vec![third_record]);
+        let second_batch: Vec<String> = dataset.since(&mut cursor).iter().map(|r| r.ID.clone()).collect();
+        assert_eq!(second_batch, vec!["3".to_string()]);
+    }
+}